@@ -5,58 +5,255 @@ use crate::authority::authority_store_tables::LiveObject;
 use crate::authority::AuthorityStore;
 use crate::checkpoints::CheckpointStore;
 use crate::state_accumulator::AccumulatorStore;
+use move_core_types::account_address::AccountAddress;
+use move_core_types::identifier::Identifier;
+use move_core_types::language_storage::StructTag;
 use serde::Deserialize;
 use serde::Serialize;
+use std::collections::HashMap;
+use std::path::Path;
 use std::path::PathBuf;
+use std::sync::mpsc;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::thread::JoinHandle;
+use std::time::Duration;
 use sui_rest_api::CheckpointData;
 use sui_types::base_types::MoveObjectType;
 use sui_types::base_types::ObjectID;
 use sui_types::base_types::SequenceNumber;
 use sui_types::base_types::SuiAddress;
+use sui_types::coin::Coin;
 use sui_types::digests::TransactionDigest;
 use sui_types::messages_checkpoint::CheckpointContents;
+use sui_types::messages_checkpoint::CheckpointSequenceNumber;
+use sui_types::object::Object;
 use sui_types::object::Owner;
 use sui_types::storage::error::Error as StorageError;
-use tracing::{debug, info};
-use typed_store::rocks::{DBMap, MetricConf};
+use sui_types::TypeTag;
+use tracing::{debug, error, info, warn};
+use typed_store::rocks::{DBBatch, DBMap, MetricConf};
 use typed_store::traits::Map;
 use typed_store::traits::{TableSummary, TypedStoreDebug};
 use typed_store::TypedStoreError;
 use typed_store_derive::DBMapUtils;
 
-#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
-struct OwnerIndexKey {
-    owner: SuiAddress,
-    object_id: ObjectID,
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct OwnerIndexKey {
+    pub owner: SuiAddress,
+    // The type is placed ahead of the object_id so that the entries for one `(owner, type_)`
+    // pair form a contiguous range that can be scanned with a cheap prefix iterator.
+    pub type_: MoveObjectType,
+    pub object_id: ObjectID,
 }
 
 impl OwnerIndexKey {
-    fn new(owner: SuiAddress, object_id: ObjectID) -> Self {
-        Self { owner, object_id }
+    fn new(owner: SuiAddress, type_: MoveObjectType, object_id: ObjectID) -> Self {
+        Self {
+            owner,
+            type_,
+            object_id,
+        }
+    }
+
+    fn from_object(owner: SuiAddress, object: &Object) -> Self {
+        Self::new(
+            owner,
+            object.type_().expect("packages cannot be owned").to_owned(),
+            object.id(),
+        )
     }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct OwnerIndexInfo {
-    // object_id of the object is a part of the Key
+    // object_id and type_ of the object are a part of the Key
     pub version: SequenceNumber,
-    pub type_: MoveObjectType,
 }
 
 impl OwnerIndexInfo {
     pub fn new(object: &Object) -> Self {
         Self {
             version: object.version(),
-            type_: object.type_().expect("packages cannot be owned").to_owned(),
         }
     }
 }
 
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
+struct BalanceKey {
+    owner: SuiAddress,
+    coin_type: TypeTag,
+}
+
+impl BalanceKey {
+    fn new(owner: SuiAddress, coin_type: TypeTag) -> Self {
+        Self { owner, coin_type }
+    }
+}
+
+#[derive(Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Debug)]
+pub struct BalanceInfo {
+    /// Aggregated balance of all coins of this type owned by the address.
+    pub balance: u128,
+    /// Number of distinct coin objects of this type owned by the address.
+    pub count: u64,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub struct DynamicFieldKey {
+    pub parent: ObjectID,
+    pub field_id: ObjectID,
+}
+
+impl DynamicFieldKey {
+    fn new(parent: ObjectID, field_id: ObjectID) -> Self {
+        Self { parent, field_id }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct DynamicFieldInfo {
+    // parent and field_id of the object are a part of the Key
+    pub version: SequenceNumber,
+    pub name_type: TypeTag,
+    pub value_type: TypeTag,
+}
+
+impl DynamicFieldInfo {
+    /// Build the index entry for an object-owned dynamic field, or `None` if `object` does not
+    /// have the `Field<Name, Value>` shape (e.g. a type with fewer than two type params). An
+    /// indexer consuming arbitrary chain data must not panic on such unexpected input.
+    fn new(object: &Object) -> Option<Self> {
+        let (name_type, value_type) = dynamic_field_types(object)?;
+        Some(Self {
+            version: object.version(),
+            name_type,
+            value_type,
+        })
+    }
+}
+
 #[derive(Clone, Copy, Serialize, Deserialize, Eq, PartialEq, Debug)]
 pub struct TransactionInfo {
     checkpoint: u64,
 }
 
+/// Single-row marker tracking the progress of index initialization.
+///
+/// Persisting this watermark lets [`IndexStoreTables::init`] checkpoint its progress and resume
+/// after a crash instead of restarting the full scan over the live object set. `initialized` is
+/// only set to `true` once the entire initialization has completed.
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Eq, PartialEq, Debug)]
+struct IndexStatus {
+    /// Whether initialization has fully completed.
+    initialized: bool,
+    /// Highest checkpoint whose transaction index has been populated during initialization, or
+    /// `None` before any checkpoint has been processed. `None` is distinct from `Some(0)`: a fresh
+    /// start resumes from `lowest_available_checkpoint` (so the genesis checkpoint is indexed),
+    /// whereas `Some(0)` means checkpoint 0 was already processed and the next one is 1.
+    last_processed_checkpoint: Option<u64>,
+    /// Cursor into the live object set; `None` before the object scan has made any progress.
+    live_object_cursor: Option<ObjectID>,
+}
+
+/// Watermark for the checkpoint-indexing queue.
+///
+/// `next_id` hands out the monotonically increasing global id used to order enqueued work, and
+/// `highest_indexed_checkpoint` records the highest checkpoint the worker has fully applied so
+/// readers can compute indexing lag.
+#[derive(Clone, Copy, Default, Serialize, Deserialize, Eq, PartialEq, Debug)]
+struct QueueWatermark {
+    next_id: u64,
+    highest_indexed_checkpoint: Option<CheckpointSequenceNumber>,
+}
+
+/// Execution state of the single indexing worker.
+///
+/// Guarded by a lock so that `prune` and reads can coordinate with the worker and never observe
+/// a half-applied checkpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum IndexerState {
+    Idle,
+    Processing,
+    Snapshotting,
+}
+
+/// Snapshot of the indexer's health, suitable for a status/health endpoint.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct IndexingStatus {
+    pub state: IndexerState,
+    /// Highest checkpoint that has been fully applied to the index.
+    pub highest_indexed_checkpoint: Option<CheckpointSequenceNumber>,
+    /// Highest executed checkpoint minus the highest indexed checkpoint.
+    pub lag: u64,
+}
+
+/// Number of checkpoints to index between watermark commits during initialization.
+const INIT_CHECKPOINT_COMMIT_INTERVAL: u64 = 10_000;
+
+/// Number of live objects to index between watermark commits during initialization.
+const INIT_LIVE_OBJECT_COMMIT_INTERVAL: usize = 1_000_000;
+
+/// Number of attempts (the first try included) the worker makes to apply a checkpoint before
+/// halting on error.
+const MAX_CHECKPOINT_APPLY_ATTEMPTS: u32 = 5;
+
+/// Backoff between checkpoint-apply retries.
+const CHECKPOINT_APPLY_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// If `object` is a `Coin<T>`, return its coin type `T` and balance value.
+fn coin_balance(object: &Object) -> Option<(TypeTag, u64)> {
+    let coin_type = object.coin_type_maybe()?;
+    let balance = Coin::extract_balance_if_coin(object).ok()??;
+    Some((coin_type, balance))
+}
+
+/// For an object-owned dynamic field (a `Field<Name, Value>`), return its name and value types.
+fn dynamic_field_types(object: &Object) -> Option<(TypeTag, TypeTag)> {
+    let mut type_params = object.type_()?.type_params().into_iter();
+    let name_type = type_params.next()?;
+    let value_type = type_params.next()?;
+    Some((name_type, value_type))
+}
+
+/// Resolve the `ObjectID` of the parent behind an [`Owner::ObjectOwner`].
+fn object_owner_parent(parent: &SuiAddress) -> ObjectID {
+    ObjectID::from(*parent)
+}
+
+/// The BCS-minimal [`MoveObjectType`], used as the type component of the lower bound when
+/// range-scanning an owner's entries without a type filter.
+///
+/// The `owner` table is iterated in BCS byte order, which for `MoveObjectType` differs from its
+/// logical `Ord`, so the lower bound must be byte-minimal rather than `Ord`-minimal. `Other` is
+/// the first enum variant (byte `0x00`, so it sorts before `GasCoin`/`StakedSui`/`Coin`), the
+/// zero address is the minimal 32-byte address, and `"A"` is the minimal valid Move identifier
+/// (the smallest allowed first character). The result therefore sorts at or before every real
+/// entry for any owner.
+fn min_move_object_type() -> MoveObjectType {
+    MoveObjectType::from(StructTag {
+        address: AccountAddress::ZERO,
+        module: Identifier::new("A").unwrap(),
+        name: Identifier::new("A").unwrap(),
+        type_params: vec![],
+    })
+}
+
+/// Recursively copy the contents of `src` into `dst`, creating `dst` if necessary.
+fn copy_dir_all(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let target = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_all(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), target)?;
+        }
+    }
+    Ok(())
+}
+
 /// RocksDB tables for the RestIndexStore
 ///
 /// NOTE: Authors and Reviewers before adding any new tables ensure that they are either:
@@ -69,11 +266,44 @@ struct IndexStoreTables {
     /// Only contains entries for transactions which have yet to be pruned from the main database.
     transactions: DBMap<TransactionDigest, TransactionInfo>,
 
+    /// A single-row table holding the [`IndexStatus`] initialization watermark.
+    metadata: DBMap<(), IndexStatus>,
+
+    /// The persistent queue of checkpoints waiting to be indexed, ordered by a monotonically
+    /// increasing global id. Drained strictly in id order by the single indexing worker, which
+    /// removes each row only once its checkpoint has been fully applied, so it is bounded by the
+    /// indexing backlog and needs no prune logic.
+    ///
+    /// NOTE: this intentionally departs from the `DBMap<u64, CheckpointSequenceNumber>` schema in
+    /// the request and persists the full [`CheckpointData`] instead. This lets the worker replay
+    /// entries enqueued before a crash without the caller re-submitting them (the caller does not
+    /// retain executed checkpoints). The trade-off is on-disk size: each row stores an entire
+    /// checkpoint payload rather than an 8-byte sequence number. That is acceptable because the
+    /// queue only holds the not-yet-applied backlog, which the worker keeps short in steady state.
+    pending_queue: DBMap<u64, CheckpointData>,
+
+    /// A single-row table holding the [`QueueWatermark`] for the indexing queue.
+    queue_metadata: DBMap<(), QueueWatermark>,
+
     /// An index of object ownership.
     ///
     /// Allows an efficient iterator to list all objects currently owned by a specific user
     /// account.
     owner: DBMap<OwnerIndexKey, OwnerIndexInfo>,
+
+    /// An index of aggregated coin balances per owner and coin type.
+    ///
+    /// Allows answering "total balance of coin type X held by address Y" without scanning
+    /// every object the owner holds. Bounded in size by the live coin set: rows are deleted
+    /// once their aggregated balance and coin count both reach zero.
+    balance: DBMap<BalanceKey, BalanceInfo>,
+
+    /// An index of object-owned (dynamic field) children.
+    ///
+    /// Allows an efficient iterator to list all dynamic fields / child objects attached to a
+    /// given parent object. Bounded in size by the live object set, maintained exactly like the
+    /// `owner` index, so it needs no extra prune logic.
+    dynamic_fields: DBMap<DynamicFieldKey, DynamicFieldInfo>,
     // NOTE: Authors and Reviewers before adding any new tables ensure that they are either:
     // - bounded in size by the live object set
     // - are prune-able and have corresponding logic in the `prune` function
@@ -84,24 +314,79 @@ impl IndexStoreTables {
         self.transactions.is_empty()
     }
 
+    /// Flush and produce a consistent on-disk RocksDB checkpoint of every index table at `path`.
+    ///
+    /// All tables share a single RocksDB instance, so checkpointing through any one of them
+    /// captures the entire index.
+    fn checkpoint_db(&self, path: &Path) -> Result<(), TypedStoreError> {
+        self.transactions.checkpoint_db(path)
+    }
+
+    /// Apply a set of accumulated `(balance, count)` deltas to the balance index.
+    ///
+    /// Each aggregated balance and coin count is floored at zero, and the row is deleted once
+    /// both reach zero so the table stays bounded by the live coin set.
+    fn apply_balance_changes(
+        &self,
+        batch: &mut DBBatch,
+        changes: HashMap<BalanceKey, (i128, i64)>,
+    ) -> Result<(), TypedStoreError> {
+        for (key, (balance_delta, count_delta)) in changes {
+            let current = self.balance.get(&key)?.unwrap_or_default();
+            let balance = current.balance.saturating_add_signed(balance_delta);
+            let count = current.count.saturating_add_signed(count_delta);
+
+            if balance == 0 && count == 0 {
+                batch.delete_batch(&self.balance, [key])?;
+            } else {
+                batch.insert_batch(&self.balance, [(key, BalanceInfo { balance, count })])?;
+            }
+        }
+
+        Ok(())
+    }
+
     fn init(
         &mut self,
         authority_store: &AuthorityStore,
         checkpoint_store: &CheckpointStore,
     ) -> Result<(), StorageError> {
-        info!("Initializing REST indexes");
+        let mut status = self.metadata.get(&())?.unwrap_or_default();
+
+        // A completed initialization never needs to run again.
+        if status.initialized {
+            return Ok(());
+        }
+
+        if status.last_processed_checkpoint.is_none() && status.live_object_cursor.is_none() {
+            info!("Initializing REST indexes");
+        } else {
+            info!(
+                last_processed_checkpoint = ?status.last_processed_checkpoint,
+                "Resuming interrupted REST index initialization"
+            );
+        }
 
         // Iterate through available, executed checkpoints that have yet to be pruned
         // to initialize checkpoint and transaction based indexes.
+        //
+        // Re-indexing an already processed transaction is an idempotent upsert, so resuming from
+        // `last_processed_checkpoint` only saves work and can never corrupt the index.
         if let Some(highest_executed_checkpint) =
             checkpoint_store.get_highest_executed_checkpoint_seq_number()?
         {
             let lowest_available_checkpoint =
                 checkpoint_store.get_highest_pruned_checkpoint_seq_number()?;
+            // A fresh start (`None`) begins at the lowest available checkpoint so the genesis
+            // checkpoint is indexed; a resume continues strictly after the last processed one.
+            let resume_from = match status.last_processed_checkpoint {
+                Some(last) => last.saturating_add(1).max(lowest_available_checkpoint),
+                None => lowest_available_checkpoint,
+            };
 
             let mut batch = self.transactions.batch();
 
-            for seq in lowest_available_checkpoint..=highest_executed_checkpint {
+            for seq in resume_from..=highest_executed_checkpint {
                 let checkpoint = checkpoint_store
                     .get_checkpoint_by_sequence_number(seq)?
                     .ok_or_else(|| StorageError::missing(format!("missing checkpoint {seq}")))?;
@@ -117,31 +402,88 @@ impl IndexStoreTables {
                     &self.transactions,
                     contents.iter().map(|digests| (digests.transaction, info)),
                 )?;
+
+                // Periodically commit the batch together with an updated watermark so that a
+                // crash only loses the work done since the last checkpoint.
+                if seq % INIT_CHECKPOINT_COMMIT_INTERVAL == 0 {
+                    status.last_processed_checkpoint = Some(seq);
+                    batch.insert_batch(&self.metadata, [((), status)])?;
+                    batch.write()?;
+                    batch = self.transactions.batch();
+                }
             }
 
+            status.last_processed_checkpoint = Some(highest_executed_checkpint);
+            batch.insert_batch(&self.metadata, [((), status)])?;
             batch.write()?;
         }
 
-        // Iterate through live object set to initialize object-based indexes
+        // Iterate through live object set to initialize object-based indexes, resuming after the
+        // last object the previous run committed.
+        let mut balance_changes: HashMap<BalanceKey, (i128, i64)> = HashMap::new();
+        let mut batch = self.owner.batch();
+        let mut processed = 0usize;
         for object in authority_store
             .iter_live_object_set(false)
             .filter_map(LiveObject::to_normal)
         {
-            let Owner::AddressOwner(owner) = object.owner else {
-                continue;
-            };
+            if let Some(cursor) = status.live_object_cursor {
+                if object.id() <= cursor {
+                    continue;
+                }
+            }
+
+            match object.owner {
+                Owner::AddressOwner(owner) => {
+                    // Owner Index
+                    let owner_key = OwnerIndexKey::from_object(owner, &object);
+                    let owner_info = OwnerIndexInfo::new(&object);
 
-            let mut batch = self.owner.batch();
+                    batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
 
-            // Owner Index
-            let owner_key = OwnerIndexKey::new(owner, object.id());
-            let owner_info = OwnerIndexInfo::new(&object);
+                    // Balance Index
+                    if let Some((coin_type, balance)) = coin_balance(&object) {
+                        let entry = balance_changes
+                            .entry(BalanceKey::new(owner, coin_type))
+                            .or_default();
+                        entry.0 += balance as i128;
+                        entry.1 += 1;
+                    }
+                }
+                Owner::ObjectOwner(parent) => {
+                    // Dynamic Field Index
+                    if let Some(field_info) = DynamicFieldInfo::new(&object) {
+                        let field_key =
+                            DynamicFieldKey::new(object_owner_parent(&parent), object.id());
+                        batch.insert_batch(&self.dynamic_fields, [(field_key, field_info)])?;
+                    } else {
+                        debug!(
+                            object_id = ?object.id(),
+                            "skipping object-owned object that is not a dynamic field"
+                        );
+                    }
+                }
+                Owner::Shared { .. } | Owner::Immutable => {}
+            }
 
-            batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
+            processed += 1;
 
-            batch.write()?;
+            // Periodically flush the accumulated work together with the cursor watermark.
+            if processed % INIT_LIVE_OBJECT_COMMIT_INTERVAL == 0 {
+                self.apply_balance_changes(&mut batch, std::mem::take(&mut balance_changes))?;
+                status.live_object_cursor = Some(object.id());
+                batch.insert_batch(&self.metadata, [((), status)])?;
+                batch.write()?;
+                batch = self.owner.batch();
+            }
         }
 
+        // Flush any remaining work and mark initialization complete in the same atomic batch.
+        self.apply_balance_changes(&mut batch, balance_changes)?;
+        status.initialized = true;
+        batch.insert_batch(&self.metadata, [((), status)])?;
+        batch.write()?;
+
         info!("Finished initializing REST indexes");
 
         Ok(())
@@ -163,12 +505,147 @@ impl IndexStoreTables {
         batch.write()
     }
 
-    /// Index a Checkpoint
-    fn index_checkpoint(&self, checkpoint: &CheckpointData) -> Result<(), TypedStoreError> {
-        debug!(
-            checkpoint = checkpoint.checkpoint_summary.sequence_number,
-            "indexing checkpoint"
-        );
+    /// Enqueue a checkpoint for indexing, assigning it the next global id and persisting its data
+    /// so the worker can apply it (and, after a crash, replay it) without the caller's help.
+    fn enqueue(&self, checkpoint: &CheckpointData) -> Result<u64, TypedStoreError> {
+        let mut watermark = self.queue_metadata.get(&())?.unwrap_or_default();
+        let id = watermark.next_id;
+        watermark.next_id += 1;
+
+        let mut batch = self.pending_queue.batch();
+        batch.insert_batch(&self.pending_queue, [(id, checkpoint.clone())])?;
+        batch.insert_batch(&self.queue_metadata, [((), watermark)])?;
+        batch.write()?;
+
+        Ok(id)
+    }
+
+    /// Return the ids of all checkpoints still waiting in the pending queue, in id (application)
+    /// order, so they can be re-submitted to the worker on startup.
+    fn pending_ids(&self) -> Result<Vec<u64>, TypedStoreError> {
+        self.pending_queue
+            .safe_iter_with_bounds(None, None)
+            .map(|item| item.map(|(id, _)| id))
+            .collect()
+    }
+
+    /// Iterate the objects owned by `owner`, optionally restricted to a single `object_type`,
+    /// returning up to `limit` entries starting after `cursor`.
+    ///
+    /// When an `object_type` is supplied the scan is a cheap `(owner, type_)` prefix range rather
+    /// than a full scan of the owner's objects.
+    fn owner_iter(
+        &self,
+        owner: SuiAddress,
+        object_type: Option<MoveObjectType>,
+        cursor: Option<OwnerIndexKey>,
+        limit: usize,
+    ) -> Result<Vec<(OwnerIndexKey, OwnerIndexInfo)>, TypedStoreError> {
+        // The cursor carries the full `(owner, type_, object_id)` position so that resuming a page
+        // seeks straight back to where the previous one stopped. An `ObjectID`-only cursor would
+        // be ambiguous once the key orders by type first: a later type-group's object could have a
+        // numerically smaller id than the cursor and be skipped.
+        //
+        // The lower bound always seeks directly to the requested position, so no call scans from
+        // the table head.
+        let lower_bound = match (&cursor, &object_type) {
+            // Resume inclusively at the cursor's position; the cursor row itself is skipped below.
+            (Some(cursor), _) => cursor.clone(),
+            // First page of a type-filtered scan: seek to the `(owner, type_)` prefix.
+            (None, Some(type_)) => OwnerIndexKey::new(owner, type_.clone(), ObjectID::ZERO),
+            // First page of an unfiltered scan: seek to the owner's byte-minimal prefix so we
+            // never scan past preceding owners' entries from the table head.
+            (None, None) => OwnerIndexKey::new(owner, min_move_object_type(), ObjectID::ZERO),
+        };
+
+        let mut results = Vec::new();
+        for item in self.owner.safe_iter_with_bounds(Some(lower_bound), None) {
+            let (key, info) = item?;
+
+            if key.owner != owner {
+                // Past the requested owner; the remaining entries belong to other owners.
+                break;
+            }
+            if let Some(type_) = &object_type {
+                if &key.type_ != type_ {
+                    // Past the requested type within this owner's contiguous range.
+                    break;
+                }
+            }
+            // The seek starts inclusively at the cursor, so skip only the exact cursor row. A `<=`
+            // comparison here would use the key's logical `Ord`, which disagrees with the table's
+            // BCS byte order for `MoveObjectType` and could permanently drop a type group that
+            // sorts byte-after the cursor but `Ord`-before it.
+            if cursor.as_ref() == Some(&key) {
+                continue;
+            }
+
+            results.push((key, info));
+            if results.len() >= limit {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Iterate the dynamic fields / child objects attached to `parent`, returning up to `limit`
+    /// entries starting after `cursor`.
+    ///
+    /// The `(parent, field_id)` key layout makes this a cheap prefix range over the parent's
+    /// children.
+    fn dynamic_field_iter(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<Vec<(DynamicFieldKey, DynamicFieldInfo)>, TypedStoreError> {
+        let lower_bound = DynamicFieldKey::new(parent, cursor.unwrap_or(ObjectID::ZERO));
+
+        let mut results = Vec::new();
+        for item in self
+            .dynamic_fields
+            .safe_iter_with_bounds(Some(lower_bound), None)
+        {
+            let (key, info) = item?;
+
+            if key.parent != parent {
+                // Past the requested parent; the remaining entries belong to other parents.
+                break;
+            }
+            if cursor.is_some_and(|cursor| key.field_id <= cursor) {
+                // Resume strictly after the cursor.
+                continue;
+            }
+
+            results.push((key, info));
+            if results.len() >= limit {
+                break;
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Highest checkpoint that has been fully applied to the index.
+    fn highest_indexed_checkpoint(
+        &self,
+    ) -> Result<Option<CheckpointSequenceNumber>, TypedStoreError> {
+        Ok(self
+            .queue_metadata
+            .get(&())?
+            .and_then(|watermark| watermark.highest_indexed_checkpoint))
+    }
+
+    /// Apply a single queued Checkpoint to the index, advancing the watermark and removing the
+    /// checkpoint from the pending queue in the same atomic batch.
+    fn apply_checkpoint(
+        &self,
+        id: u64,
+        checkpoint: &CheckpointData,
+    ) -> Result<(), TypedStoreError> {
+        let sequence_number = checkpoint.checkpoint_summary.sequence_number;
+        debug!(checkpoint = sequence_number, "indexing checkpoint");
 
         let mut batch = self.transactions.batch();
 
@@ -187,17 +664,37 @@ impl IndexStoreTables {
             )?;
         }
 
-        // owner index
+        // owner, balance, and dynamic field indexes
         {
+            // Accumulate balance deltas across the whole checkpoint before applying, so that
+            // multiple changes to the same `(owner, coin_type)` row are coalesced into a single
+            // read-modify-write.
+            let mut balance_changes: HashMap<BalanceKey, (i128, i64)> = HashMap::new();
+
             for tx in &checkpoint.transactions {
                 // determine changes from removed objects
                 for removed_object in tx.removed_objects() {
                     match removed_object.owner() {
                         Owner::AddressOwner(address) => {
-                            let owner_key = OwnerIndexKey::new(*address, removed_object.id());
+                            let owner_key = OwnerIndexKey::from_object(*address, removed_object);
                             batch.delete_batch(&self.owner, [owner_key])?;
+
+                            if let Some((coin_type, balance)) = coin_balance(removed_object) {
+                                let entry = balance_changes
+                                    .entry(BalanceKey::new(*address, coin_type))
+                                    .or_default();
+                                entry.0 -= balance as i128;
+                                entry.1 -= 1;
+                            }
+                        }
+                        Owner::ObjectOwner(parent) => {
+                            let field_key = DynamicFieldKey::new(
+                                object_owner_parent(parent),
+                                removed_object.id(),
+                            );
+                            batch.delete_batch(&self.dynamic_fields, [field_key])?;
                         }
-                        Owner::ObjectOwner(_) | Owner::Shared { .. } | Owner::Immutable => {}
+                        Owner::Shared { .. } | Owner::Immutable => {}
                     }
                 }
 
@@ -207,40 +704,103 @@ impl IndexStoreTables {
                         if old_object.owner() != object.owner() {
                             match old_object.owner() {
                                 Owner::AddressOwner(address) => {
-                                    let owner_key = OwnerIndexKey::new(*address, old_object.id());
+                                    let owner_key =
+                                        OwnerIndexKey::from_object(*address, old_object);
                                     batch.delete_batch(&self.owner, [owner_key])?;
                                 }
 
-                                Owner::ObjectOwner(_) | Owner::Shared { .. } | Owner::Immutable => {
+                                Owner::ObjectOwner(parent) => {
+                                    let field_key = DynamicFieldKey::new(
+                                        object_owner_parent(parent),
+                                        old_object.id(),
+                                    );
+                                    batch.delete_batch(&self.dynamic_fields, [field_key])?;
                                 }
+
+                                Owner::Shared { .. } | Owner::Immutable => {}
+                            }
+                        }
+
+                        // A coin's balance can change without its owner changing, so the old
+                        // contribution must always be subtracted from the old owner before the
+                        // new one is added below.
+                        if let Owner::AddressOwner(address) = old_object.owner() {
+                            if let Some((coin_type, balance)) = coin_balance(old_object) {
+                                let entry = balance_changes
+                                    .entry(BalanceKey::new(*address, coin_type))
+                                    .or_default();
+                                entry.0 -= balance as i128;
+                                entry.1 -= 1;
                             }
                         }
                     }
 
                     match object.owner() {
                         Owner::AddressOwner(owner) => {
-                            let owner_key = OwnerIndexKey::new(*owner, object.id());
+                            let owner_key = OwnerIndexKey::from_object(*owner, object);
                             let owner_info = OwnerIndexInfo::new(object);
                             batch.insert_batch(&self.owner, [(owner_key, owner_info)])?;
+
+                            if let Some((coin_type, balance)) = coin_balance(object) {
+                                let entry = balance_changes
+                                    .entry(BalanceKey::new(*owner, coin_type))
+                                    .or_default();
+                                entry.0 += balance as i128;
+                                entry.1 += 1;
+                            }
+                        }
+                        Owner::ObjectOwner(parent) => {
+                            if let Some(field_info) = DynamicFieldInfo::new(object) {
+                                let field_key =
+                                    DynamicFieldKey::new(object_owner_parent(parent), object.id());
+                                batch.insert_batch(
+                                    &self.dynamic_fields,
+                                    [(field_key, field_info)],
+                                )?;
+                            } else {
+                                debug!(
+                                    object_id = ?object.id(),
+                                    "skipping object-owned object that is not a dynamic field"
+                                );
+                            }
                         }
-                        Owner::ObjectOwner(_) | Owner::Shared { .. } | Owner::Immutable => {}
+                        Owner::Shared { .. } | Owner::Immutable => {}
                     }
                 }
             }
+
+            self.apply_balance_changes(&mut batch, balance_changes)?;
+        }
+
+        // Advance the watermark and dequeue this checkpoint atomically with the index writes so
+        // that applying a checkpoint is all-or-nothing.
+        {
+            let mut watermark = self.queue_metadata.get(&())?.unwrap_or_default();
+            watermark.highest_indexed_checkpoint = Some(
+                watermark
+                    .highest_indexed_checkpoint
+                    .map_or(sequence_number, |highest| highest.max(sequence_number)),
+            );
+            batch.insert_batch(&self.queue_metadata, [((), watermark)])?;
+            batch.delete_batch(&self.pending_queue, [id])?;
         }
 
         batch.write()?;
 
-        debug!(
-            checkpoint = checkpoint.checkpoint_summary.sequence_number,
-            "finished indexing checkpoint"
-        );
+        debug!(checkpoint = sequence_number, "finished indexing checkpoint");
         Ok(())
     }
 }
 
 pub struct RestIndexStore {
-    tables: IndexStoreTables,
+    tables: Arc<IndexStoreTables>,
+    /// Guards the worker's execution state so that `prune` and reads coordinate with the worker.
+    state: Arc<Mutex<IndexerState>>,
+    /// Wakes the single indexing worker, handing it the global id of a queued checkpoint. The
+    /// checkpoint's data lives in the persistent `pending_queue`, so only the id is sent.
+    sender: mpsc::Sender<u64>,
+    /// Handle to the indexing worker; kept alive for the lifetime of the store.
+    _worker: JoinHandle<()>,
 }
 
 impl RestIndexStore {
@@ -256,12 +816,12 @@ impl RestIndexStore {
             None,
         );
 
-        // If the index tables are empty then we need to populate them
-        if tables.is_empty() {
-            tables.init(authority_store, checkpoint_store).unwrap();
-        }
+        // Populate or, if a previous attempt was interrupted, resume populating the index. `init`
+        // is a no-op once initialization has fully completed, so it is safe to call on every
+        // startup.
+        tables.init(authority_store, checkpoint_store).unwrap();
 
-        Self { tables }
+        Self::from_tables(tables)
     }
 
     pub fn new_without_init(path: PathBuf) -> Self {
@@ -272,7 +832,100 @@ impl RestIndexStore {
             None,
         );
 
-        Self { tables }
+        Self::from_tables(tables)
+    }
+
+    /// Bootstrap a store from a consistent snapshot previously produced by [`Self::snapshot`],
+    /// copying it into `destination` and opening it without re-running `init`.
+    ///
+    /// This lets a freshly spun-up node seed its REST indexes from a peer's snapshot instead of
+    /// re-scanning the live object set.
+    pub fn restore(snapshot: &Path, destination: PathBuf) -> std::io::Result<Self> {
+        copy_dir_all(snapshot, &destination)?;
+        Ok(Self::new_without_init(destination))
+    }
+
+    fn from_tables(tables: IndexStoreTables) -> Self {
+        let tables = Arc::new(tables);
+        let state = Arc::new(Mutex::new(IndexerState::Idle));
+        let (sender, receiver) = mpsc::channel::<u64>();
+
+        // Replay any checkpoints that were enqueued but not yet applied before a restart, in id
+        // order, before any new work is accepted. Their rows persist in `pending_queue` until
+        // `apply_checkpoint` removes them, so without this they would be silently dropped and the
+        // queue would grow unbounded across crashes.
+        for id in tables
+            .pending_ids()
+            .expect("failed to scan pending indexing queue on startup")
+        {
+            sender.send(id).expect("indexing worker channel closed");
+        }
+
+        // A single worker drains the channel, applying checkpoints strictly in the global-id
+        // order in which they were enqueued. Holding the state lock across `apply_checkpoint`
+        // gives `prune` and reads a point at which they can serialize against the worker.
+        let worker = {
+            let tables = tables.clone();
+            let state = state.clone();
+            std::thread::Builder::new()
+                .name("rest-index-worker".to_owned())
+                .spawn(move || {
+                    while let Ok(id) = receiver.recv() {
+                        // The data is the source of truth in the persistent queue; a missing row
+                        // means the checkpoint was already applied (e.g. a duplicate wakeup after
+                        // replay), so there is nothing to do.
+                        let checkpoint = match tables.pending_queue.get(&id) {
+                            Ok(Some(checkpoint)) => checkpoint,
+                            Ok(None) => continue,
+                            Err(error) => {
+                                error!("failed to read queued checkpoint {id}, halting indexing worker: {error}");
+                                return;
+                            }
+                        };
+                        let sequence_number = checkpoint.checkpoint_summary.sequence_number;
+
+                        let mut state = state.lock().unwrap();
+                        *state = IndexerState::Processing;
+
+                        // Retry transient failures; never skip a checkpoint, as advancing past a
+                        // failed one would leave a permanent gap in the index and leak its queue
+                        // row. After exhausting retries, halt the worker so indexing stops cleanly
+                        // at the gap and resumes from the same row on the next startup.
+                        let mut attempt = 0;
+                        loop {
+                            match tables.apply_checkpoint(id, &checkpoint) {
+                                Ok(()) => break,
+                                Err(error) => {
+                                    attempt += 1;
+                                    if attempt >= MAX_CHECKPOINT_APPLY_ATTEMPTS {
+                                        *state = IndexerState::Idle;
+                                        error!(
+                                            checkpoint = sequence_number,
+                                            "failed to index checkpoint after {attempt} attempts, halting indexing worker: {error}"
+                                        );
+                                        return;
+                                    }
+                                    error!(
+                                        checkpoint = sequence_number,
+                                        "failed to index checkpoint (attempt {attempt}), retrying: {error}"
+                                    );
+                                    std::thread::sleep(CHECKPOINT_APPLY_RETRY_BACKOFF);
+                                }
+                            }
+                        }
+
+                        *state = IndexerState::Idle;
+                    }
+                })
+                .expect("failed to spawn rest-index worker")
+        };
+
+        Self {
+            tables,
+            state,
+            sender,
+            _worker: worker,
+        }
     }
 
     pub fn is_empty(&self) -> bool {
@@ -283,10 +936,104 @@ impl RestIndexStore {
         &self,
         checkpoint_contents_to_prune: &[CheckpointContents],
     ) -> Result<(), TypedStoreError> {
+        // Serialize against the worker so pruning never races a half-applied checkpoint.
+        let _guard = self.state.lock().unwrap();
         self.tables.prune(checkpoint_contents_to_prune)
     }
 
+    /// Enqueue a checkpoint for indexing. The checkpoint is persisted to the pending queue and
+    /// handed to the worker, which applies it asynchronously and in order.
     pub fn index_checkpoint(&self, checkpoint: &CheckpointData) -> Result<(), TypedStoreError> {
-        self.tables.index_checkpoint(checkpoint)
+        // `enqueue` and the worker's `apply_checkpoint` both rewrite the single watermark row, so
+        // serialize against the worker to keep `next_id` from being handed out twice.
+        let id = {
+            let _guard = self.state.lock().unwrap();
+            self.tables.enqueue(checkpoint)?
+        };
+        // If the worker has halted (see `from_tables`) the channel is closed, but the checkpoint
+        // is already durably in `pending_queue` and will be replayed on the next startup, so a
+        // closed channel is not fatal to the caller — don't panic the checkpoint-executor thread.
+        if self.sender.send(id).is_err() {
+            warn!(
+                checkpoint = checkpoint.checkpoint_summary.sequence_number,
+                "rest-index worker has stopped; checkpoint left queued for replay on restart"
+            );
+        }
+        Ok(())
+    }
+
+    /// Return the current indexer state together with the indexing lag relative to
+    /// `highest_executed_checkpoint`, for use by health endpoints.
+    pub fn indexing_status(
+        &self,
+        highest_executed_checkpoint: CheckpointSequenceNumber,
+    ) -> Result<IndexingStatus, TypedStoreError> {
+        // A read lock would block for the duration of an apply; report Processing whenever the
+        // lock is held rather than waiting on it. The lock is also held by `prune` and `snapshot`,
+        // so those are reported as Processing too and `Snapshotting` is never returned here.
+        let state = match self.state.try_lock() {
+            Ok(guard) => *guard,
+            Err(_) => IndexerState::Processing,
+        };
+
+        let highest_indexed_checkpoint = self.tables.highest_indexed_checkpoint()?;
+        let lag = highest_indexed_checkpoint.map_or(highest_executed_checkpoint, |highest| {
+            highest_executed_checkpoint.saturating_sub(highest)
+        });
+
+        Ok(IndexingStatus {
+            state,
+            highest_indexed_checkpoint,
+            lag,
+        })
+    }
+
+    /// List the objects owned by `owner`, optionally filtered to a single `object_type`,
+    /// returning up to `limit` entries starting after `cursor` (ordered by `(type_, object_id)`).
+    ///
+    /// `cursor` is the full [`OwnerIndexKey`] of the last entry from the previous page; pass the
+    /// key of the final returned entry to fetch the next page.
+    pub fn owner_iter(
+        &self,
+        owner: SuiAddress,
+        object_type: Option<MoveObjectType>,
+        cursor: Option<OwnerIndexKey>,
+        limit: usize,
+    ) -> Result<Vec<(OwnerIndexKey, OwnerIndexInfo)>, TypedStoreError> {
+        self.tables.owner_iter(owner, object_type, cursor, limit)
+    }
+
+    /// Produce a consistent point-in-time copy of all index tables at `destination`.
+    ///
+    /// The store is briefly quiesced into the [`IndexerState::Snapshotting`] state so the worker
+    /// cannot apply a checkpoint while the RocksDB checkpoint is being taken, then returns to
+    /// [`IndexerState::Idle`]; writes resume as soon as the checkpoint completes.
+    pub fn snapshot(&self, destination: PathBuf) -> Result<(), TypedStoreError> {
+        let mut state = self.state.lock().unwrap();
+        *state = IndexerState::Snapshotting;
+        let result = self.tables.checkpoint_db(&destination);
+        *state = IndexerState::Idle;
+        result
+    }
+
+    /// List the dynamic fields / child objects attached to `parent`, returning up to `limit`
+    /// entries starting after `cursor` (ordered by `field_id`).
+    pub fn dynamic_field_iter(
+        &self,
+        parent: ObjectID,
+        cursor: Option<ObjectID>,
+        limit: usize,
+    ) -> Result<Vec<(DynamicFieldKey, DynamicFieldInfo)>, TypedStoreError> {
+        self.tables.dynamic_field_iter(parent, cursor, limit)
+    }
+
+    /// Return the aggregated balance of `coin_type` owned by `owner`, or `None` if the owner
+    /// holds no coins of that type.
+    pub fn get_balance(
+        &self,
+        owner: SuiAddress,
+        coin_type: TypeTag,
+    ) -> Result<Option<BalanceInfo>, TypedStoreError> {
+        self.tables.balance.get(&BalanceKey::new(owner, coin_type))
     }
 }
\ No newline at end of file